@@ -0,0 +1,41 @@
+// Original work Copyright 2016 Alexander Stocko <as@coder.gg>.
+// Modified work Copyright 2023 Daan Vanoverloop
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::init::TypedInit;
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, TableGenError>;
+
+/// Errors produced while walking a parsed TableGen record graph.
+#[derive(Debug)]
+pub enum TableGenError {
+    /// A `TypedInit` was converted via `TryFrom` into a variant it doesn't
+    /// hold.
+    IncorrectInitType(TypedInit),
+    /// A `Bit` init held a value other than 0 or 1.
+    InvalidBitRange,
+    /// The underlying C API returned a null pointer where a value was
+    /// expected.
+    NullPointer,
+}
+
+impl fmt::Display for TableGenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableGenError::IncorrectInitType(init) => {
+                write!(f, "value was not of the requested type: {init:?}")
+            }
+            TableGenError::InvalidBitRange => write!(f, "bit init was not 0 or 1"),
+            TableGenError::NullPointer => write!(f, "unexpected null pointer from TableGen"),
+        }
+    }
+}
+
+impl std::error::Error for TableGenError {}
@@ -10,8 +10,9 @@
 
 use crate::raw::{
     tableGenBitArrayFree, tableGenBitInitGetValue, tableGenBitsInitGetValue,
-    tableGenDagRecordArgName, tableGenDagRecordGet, tableGenDagRecordNumArgs,
-    tableGenDefInitGetValue, tableGenInitRecType, tableGenIntInitGetValue, tableGenListRecordGet,
+    tableGenDagRecordArgName, tableGenDagRecordGet, tableGenDagRecordGetOperator,
+    tableGenDagRecordNumArgs, tableGenDagRecordOperatorName, tableGenDefInitGetValue,
+    tableGenInitRecType, tableGenIntInitGetValue, tableGenListRecordGet,
     tableGenListRecordNumElements, tableGenStringInitGetValueNewString, TableGenRecTyKind,
     TableGenTypedInitRef,
 };
@@ -92,6 +93,10 @@ impl TypedInit {
     as_inner!(dag, Dag, DagInit);
     as_inner!(def, Record, Record);
 
+    /// # Safety
+    ///
+    /// `init` must be a valid, non-null `TableGenTypedInitRef` obtained from
+    /// the TableGen C API.
     #[allow(non_upper_case_globals)]
     pub unsafe fn from_raw(init: TableGenTypedInitRef) -> error::Result<Self> {
         let t = tableGenInitRecType(init);
@@ -119,7 +124,7 @@ impl TypedInit {
                 }
                 tableGenBitArrayFree(cbits);
                 if bits.is_empty() {
-                    Err(TableGenError::NullPointer.into())
+                    Err(TableGenError::NullPointer)
                 } else {
                     Ok(TypedInit::Bits(bits))
                 }
@@ -148,17 +153,22 @@ impl TypedInit {
 #[derive(Debug)]
 pub struct DagInit {
     raw: TableGenTypedInitRef,
+    cache: std::cell::OnceCell<Vec<TypedInit>>,
 }
 
 impl DagInit {
     pub fn from_raw(val: TableGenTypedInitRef) -> DagInit {
-        DagInit { raw: val }
+        DagInit {
+            raw: val,
+            cache: std::cell::OnceCell::new(),
+        }
     }
 
-    pub fn args(&self) -> DagIter {
+    pub fn args(&self) -> DagIter<'_> {
         DagIter {
             dag: self,
             index: 0,
+            end: self.num_args(),
         }
     }
 
@@ -166,6 +176,30 @@ impl DagInit {
         unsafe { tableGenDagRecordNumArgs(self.raw) }
     }
 
+    /// The dag's operator node, e.g. the `ins` in `(ins GPR:$a, GPR:$b)`.
+    ///
+    /// Combined with [`DagInit::args`], this lets a consumer reconstruct the
+    /// full `(operator arg1:$name1, arg2:$name2, ...)` form of the dag.
+    pub fn operator(&self) -> Option<TypedInit> {
+        let value = unsafe { tableGenDagRecordGetOperator(self.raw) };
+        if !value.is_null() {
+            unsafe { TypedInit::from_raw(value).ok() }
+        } else {
+            None
+        }
+    }
+
+    /// The name of [`DagInit::operator`], if the operator is itself a named
+    /// def rather than an anonymous expression.
+    pub fn operator_name(&self) -> Option<String> {
+        let value = unsafe { tableGenDagRecordOperatorName(self.raw) };
+        if !value.is_null() {
+            Some(unsafe { CStr::from_ptr(value).to_string_lossy().into_owned() })
+        } else {
+            None
+        }
+    }
+
     pub fn name(&self, index: usize) -> Option<String> {
         let value = unsafe { tableGenDagRecordArgName(self.raw, index) };
         if !value.is_null() {
@@ -184,46 +218,111 @@ impl DagInit {
         }
     }
 
+    /// # Safety
+    ///
+    /// `index` must be less than [`DagInit::num_args`]; an out-of-range
+    /// index is undefined behavior in the underlying C API.
     pub unsafe fn get_unchecked(&self, index: usize) -> Option<TypedInit> {
         TypedInit::from_raw(tableGenDagRecordGet(self.raw, index)).ok()
     }
+
+    // Backs `Index`: `TypedInit` values are reconstructed from the C API on
+    // every `get()`, so there is nothing for a reference to borrow from
+    // until the first indexing access materializes every argument once.
+    fn cached_args(&self) -> &[TypedInit] {
+        self.cache.get_or_init(|| {
+            (0..self.num_args())
+                .map(|i| self.get(i).unwrap_or(TypedInit::Invalid))
+                .collect()
+        })
+    }
+}
+
+/// Indexes into a dag's positional arguments by value, ignoring argument
+/// names; use [`DagInit::name`] to look up the name at the same index.
+impl std::ops::Index<usize> for DagInit {
+    type Output = TypedInit;
+
+    fn index(&self, index: usize) -> &TypedInit {
+        &self.cached_args()[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a DagInit {
+    type Item = (Option<String>, TypedInit);
+    type IntoIter = DagIter<'a>;
+
+    fn into_iter(self) -> DagIter<'a> {
+        self.args()
+    }
 }
 
+/// Iterates a dag's `(name, value)` argument pairs. `name` is `None` for
+/// unnamed positional operands, e.g. the `1` in `(add GPR:$a, 1)` — those
+/// are common and must not be mistaken for the end of the dag.
 #[derive(Debug, Clone)]
 pub struct DagIter<'a> {
     dag: &'a DagInit,
     index: usize,
+    end: usize,
 }
 
 impl<'a> Iterator for DagIter<'a> {
-    type Item = (String, TypedInit);
+    type Item = (Option<String>, TypedInit);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.dag.get(self.index);
+        if self.index >= self.end {
+            return None;
+        }
         let name = self.dag.name(self.index);
+        let value = self.dag.get(self.index).unwrap_or(TypedInit::Invalid);
         self.index += 1;
-        if next.is_some() && name.is_some() {
-            Some((name.unwrap(), next.unwrap()))
-        } else {
-            None
+        Some((name, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for DagIter<'a> {
+    fn len(&self) -> usize {
+        self.end - self.index
+    }
+}
+
+impl<'a> DoubleEndedIterator for DagIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
         }
+        self.end -= 1;
+        let name = self.dag.name(self.end);
+        let value = self.dag.get(self.end).unwrap_or(TypedInit::Invalid);
+        Some((name, value))
     }
 }
 
 #[derive(Debug)]
 pub struct ListInit {
     raw: TableGenTypedInitRef,
+    cache: std::cell::OnceCell<Vec<TypedInit>>,
 }
 
 impl ListInit {
     pub fn from_raw(val: TableGenTypedInitRef) -> ListInit {
-        ListInit { raw: val }
+        ListInit {
+            raw: val,
+            cache: std::cell::OnceCell::new(),
+        }
     }
 
-    pub fn iter(&self) -> ListIter {
+    pub fn iter(&self) -> ListIter<'_> {
         ListIter {
             list: self,
             index: 0,
+            end: self.len(),
         }
     }
 
@@ -231,6 +330,10 @@ impl ListInit {
         unsafe { tableGenListRecordNumElements(self.raw) }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn get(&self, index: usize) -> Option<TypedInit> {
         let value = unsafe { tableGenListRecordGet(self.raw, index) };
         if !value.is_null() {
@@ -240,21 +343,56 @@ impl ListInit {
         }
     }
 
+    /// # Safety
+    ///
+    /// `index` must be less than [`ListInit::len`]; an out-of-range index
+    /// is undefined behavior in the underlying C API.
     pub unsafe fn get_unchecked(&self, index: usize) -> Option<TypedInit> {
         TypedInit::from_raw(tableGenListRecordGet(self.raw, index)).ok()
     }
+
+    // Backs `Index`: see `DagInit::cached_args` for why this has to
+    // materialize elements instead of borrowing straight from the C API.
+    fn cached_elements(&self) -> &[TypedInit] {
+        self.cache.get_or_init(|| {
+            (0..self.len())
+                .map(|i| self.get(i).unwrap_or(TypedInit::Invalid))
+                .collect()
+        })
+    }
+}
+
+impl std::ops::Index<usize> for ListInit {
+    type Output = TypedInit;
+
+    fn index(&self, index: usize) -> &TypedInit {
+        &self.cached_elements()[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a ListInit {
+    type Item = TypedInit;
+    type IntoIter = ListIter<'a>;
+
+    fn into_iter(self) -> ListIter<'a> {
+        self.iter()
+    }
 }
 
 #[derive(Clone)]
 pub struct ListIter<'a> {
     list: &'a ListInit,
     index: usize,
+    end: usize,
 }
 
 impl<'a> Iterator for ListIter<'a> {
     type Item = TypedInit;
 
     fn next(&mut self) -> Option<TypedInit> {
+        if self.index >= self.end {
+            return None;
+        }
         let next = unsafe { tableGenListRecordGet(self.list.raw, self.index) };
         self.index += 1;
         if !next.is_null() {
@@ -263,4 +401,528 @@ impl<'a> Iterator for ListIter<'a> {
             None
         }
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for ListIter<'a> {
+    fn len(&self) -> usize {
+        self.end - self.index
+    }
+}
+
+impl<'a> DoubleEndedIterator for ListIter<'a> {
+    fn next_back(&mut self) -> Option<TypedInit> {
+        if self.index >= self.end {
+            return None;
+        }
+        self.end -= 1;
+        let next = unsafe { tableGenListRecordGet(self.list.raw, self.end) };
+        if !next.is_null() {
+            unsafe { TypedInit::from_raw(next).ok() }
+        } else {
+            None
+        }
+    }
+}
+
+// Mirrors llvm-tblgen's `--dump-json` encoding so a parsed record graph can be
+// piped into tools that already consume the upstream JSON format.
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
+
+#[cfg(feature = "serde")]
+impl Serialize for TypedInit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TypedInit::Bit(bit) => serializer.serialize_i8(*bit),
+            TypedInit::Int(int) => serializer.serialize_i64(*int),
+            // Bits are emitted in the same order they are stored in the
+            // vector, i.e. index 0 (the least-significant bit, as returned by
+            // `tableGenBitsInitGetValue`) first.
+            TypedInit::Bits(bits) => {
+                let mut seq = serializer.serialize_seq(Some(bits.len()))?;
+                for bit in bits {
+                    seq.serialize_element(bit)?;
+                }
+                seq.end()
+            }
+            TypedInit::String(string) | TypedInit::Code(string) => serializer.serialize_str(string),
+            TypedInit::List(list) => list.serialize(serializer),
+            TypedInit::Dag(dag) => dag.serialize(serializer),
+            // Records are serialized as a reference rather than their
+            // expanded body so that recursive defs don't produce an
+            // infinite tree.
+            TypedInit::Record(record) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("kind", "def")?;
+                map.serialize_entry("def", record.name())?;
+                map.end()
+            }
+            TypedInit::Invalid => serializer.serialize_unit(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ListInit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for item in self.iter() {
+            seq.serialize_element(&item)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct DagArg {
+    name: Option<String>,
+    value: TypedInit,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DagInit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let args: Vec<DagArg> = self
+            .args()
+            .map(|(name, value)| DagArg { name, value })
+            .collect();
+
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry("operator", &self.operator())?;
+        map.serialize_entry("args", &args)?;
+        map.end()
+    }
+}
+
+/// Deserialization support for populating user-defined Rust types directly
+/// from a parsed `TypedInit` tree, so callers don't have to hand-walk the
+/// `as_*`/`TryFrom` accessors on [`TypedInit`] themselves.
+///
+/// ```ignore
+/// #[derive(serde::Deserialize)]
+/// struct Instr {
+///     opcode: i64,
+///     operands: Vec<String>,
+/// }
+///
+/// let instr: Instr = de::from_record(&record)?;
+/// ```
+#[cfg(feature = "serde")]
+pub mod de {
+    use super::{DagInit, TypedInit};
+    use crate::record::Record;
+    use serde::de::{
+        self, Deserialize, DeserializeSeed, Error as _, IntoDeserializer, MapAccess, SeqAccess,
+        Visitor,
+    };
+    use std::fmt;
+
+    /// Deserialize a `T` out of `record`'s named field values.
+    pub fn from_record<'de, T>(record: &Record) -> Result<T, Error>
+    where
+        T: Deserialize<'de>,
+    {
+        T::deserialize(RecordDeserializer(record))
+    }
+
+    /// Deserialize a `T` out of a single [`TypedInit`] (e.g. one field of a
+    /// record, or an element of a [`ListInit`](super::ListInit)/[`DagInit`]).
+    pub fn from_init<'de, T>(init: TypedInit) -> Result<T, Error>
+    where
+        T: Deserialize<'de>,
+    {
+        T::deserialize(InitDeserializer(init))
+    }
+
+    /// An error produced while mapping a `TypedInit` tree onto a Rust type.
+    #[derive(Debug)]
+    pub enum Error {
+        /// A record did not have a value with the requested field name.
+        MissingField(String),
+        /// Catch-all for errors raised by `serde`'s derived `Deserialize`
+        /// impls (e.g. `Invalid` inits, or a custom `visit_*` failure).
+        Message(String),
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::MissingField(name) => write!(f, "missing field `{name}`"),
+                Error::Message(msg) => f.write_str(msg),
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl de::Error for Error {
+        fn custom<T: fmt::Display>(msg: T) -> Self {
+            Error::Message(msg.to_string())
+        }
+
+        fn missing_field(field: &'static str) -> Self {
+            Error::MissingField(field.to_string())
+        }
+    }
+
+    /// Deserializer over a single [`TypedInit`] value.
+    struct InitDeserializer(TypedInit);
+
+    impl<'de> de::Deserializer<'de> for InitDeserializer {
+        type Error = Error;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                TypedInit::Bit(bit) => visitor.visit_bool(bit != 0),
+                TypedInit::Int(int) => visitor.visit_i64(int),
+                TypedInit::Bits(bits) => {
+                    let elements = bits.into_iter().map(TypedInit::Bit);
+                    visitor.visit_seq(OwnedSeqAccess::new(elements))
+                }
+                TypedInit::String(string) | TypedInit::Code(string) => visitor.visit_string(string),
+                TypedInit::List(list) => visitor.visit_seq(OwnedSeqAccess::new(list.iter())),
+                TypedInit::Dag(dag) => visitor.visit_map(DagAccess::new(&dag)),
+                TypedInit::Record(record) => visitor.visit_map(RecordFieldsAccess::new(&record)),
+                TypedInit::Invalid => Err(Error::custom("cannot deserialize an Invalid init")),
+            }
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                TypedInit::Invalid => visitor.visit_none(),
+                other => visitor.visit_some(InitDeserializer(other)),
+            }
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    /// Deserializer over a [`Record`]'s named fields, used as the entry
+    /// point for structs (one struct field per named value).
+    struct RecordDeserializer<'a>(&'a Record);
+
+    impl<'de, 'a> de::Deserializer<'de> for RecordDeserializer<'a> {
+        type Error = Error;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_map(RecordFieldsAccess::new(self.0))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    /// Walks a record's named values as a serde map, so a derived
+    /// `Deserialize` can pull each struct field out by name.
+    struct RecordFieldsAccess<'a> {
+        record: &'a Record,
+        fields: std::vec::IntoIter<String>,
+        value: Option<TypedInit>,
+    }
+
+    impl<'a> RecordFieldsAccess<'a> {
+        fn new(record: &'a Record) -> Self {
+            RecordFieldsAccess {
+                record,
+                fields: record.fields().into_iter(),
+                value: None,
+            }
+        }
+    }
+
+    impl<'de, 'a> MapAccess<'de> for RecordFieldsAccess<'a> {
+        type Error = Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            let Some(name) = self.fields.next() else {
+                return Ok(None);
+            };
+            match self.record.value(&name) {
+                Some(value) => {
+                    self.value = Some(value);
+                    seed.deserialize(name.into_deserializer()).map(Some)
+                }
+                None => Err(Error::MissingField(name)),
+            }
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            let value = self.value.take().ok_or_else(|| {
+                Error::custom("next_value_seed called before next_key_seed")
+            })?;
+            seed.deserialize(InitDeserializer(value))
+        }
+    }
+
+    /// Walks a dag's arguments as a serde map keyed by argument name, so a
+    /// derived `Deserialize` can pull `(ins GPR:$dst, GPR:$src)` apart as
+    /// `{ dst: ..., src: ... }`.
+    struct DagAccess<'a> {
+        iter: super::DagIter<'a>,
+        value: Option<TypedInit>,
+    }
+
+    impl<'a> DagAccess<'a> {
+        fn new(dag: &'a DagInit) -> Self {
+            DagAccess {
+                iter: dag.args(),
+                value: None,
+            }
+        }
+    }
+
+    impl<'de, 'a> MapAccess<'de> for DagAccess<'a> {
+        type Error = Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            // Unnamed positional operands (e.g. the 1 in `(add GPR:$a, 1)`)
+            // have no key to offer a struct field, so they're skipped here
+            // rather than surfaced as a map entry.
+            loop {
+                let Some((name, value)) = self.iter.next() else {
+                    return Ok(None);
+                };
+                if let Some(name) = name {
+                    self.value = Some(value);
+                    return seed.deserialize(name.into_deserializer()).map(Some);
+                }
+            }
+        }
+
+        fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+        where
+            V: DeserializeSeed<'de>,
+        {
+            let value = self.value.take().ok_or_else(|| {
+                Error::custom("next_value_seed called before next_key_seed")
+            })?;
+            seed.deserialize(InitDeserializer(value))
+        }
+    }
+
+    /// Walks any `Iterator<Item = TypedInit>` (a list's elements, or a
+    /// `Bits`' individual bits) as a serde sequence.
+    struct OwnedSeqAccess<I> {
+        iter: I,
+    }
+
+    impl<I> OwnedSeqAccess<I> {
+        fn new(iter: I) -> Self {
+            OwnedSeqAccess { iter }
+        }
+    }
+
+    impl<'de, I> SeqAccess<'de> for OwnedSeqAccess<I>
+    where
+        I: Iterator<Item = TypedInit>,
+    {
+        type Error = Error;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            self.iter
+                .next()
+                .map(|value| seed.deserialize(InitDeserializer(value)))
+                .transpose()
+        }
+    }
+}
+
+// Structural hashing, so backend generators can tell whether two records
+// expand to identical operand/pattern structures and share tables between
+// them instead of re-emitting duplicates.
+//
+// `Record` is hashed/compared by name only rather than by its expanded body:
+// that keeps the walk bounded and cycle-free, at the cost of treating two
+// distinct records with identical bodies as unequal (they're different defs
+// with possibly-diverging future expansions, so that's the right call here).
+impl std::hash::Hash for TypedInit {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            TypedInit::Bit(bit) => bit.hash(state),
+            // `Vec<i8>`'s own `Hash` impl already feeds the length before
+            // the elements.
+            TypedInit::Bits(bits) => bits.hash(state),
+            TypedInit::Code(string) | TypedInit::String(string) => string.hash(state),
+            TypedInit::Int(int) => int.hash(state),
+            TypedInit::List(list) => list.hash(state),
+            TypedInit::Dag(dag) => dag.hash(state),
+            TypedInit::Record(record) => record.name().hash(state),
+            TypedInit::Invalid => {}
+        }
+    }
+}
+
+impl PartialEq for TypedInit {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TypedInit::Bit(a), TypedInit::Bit(b)) => a == b,
+            (TypedInit::Bits(a), TypedInit::Bits(b)) => a == b,
+            (TypedInit::Code(a), TypedInit::Code(b)) => a == b,
+            (TypedInit::Int(a), TypedInit::Int(b)) => a == b,
+            (TypedInit::String(a), TypedInit::String(b)) => a == b,
+            (TypedInit::List(a), TypedInit::List(b)) => a == b,
+            (TypedInit::Dag(a), TypedInit::Dag(b)) => a == b,
+            (TypedInit::Record(a), TypedInit::Record(b)) => a.name() == b.name(),
+            (TypedInit::Invalid, TypedInit::Invalid) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for TypedInit {}
+
+impl std::hash::Hash for ListInit {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl PartialEq for ListInit {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for ListInit {}
+
+impl std::hash::Hash for DagInit {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.operator().hash(state);
+        for (name, value) in self.args() {
+            name.hash(state);
+            value.hash(state);
+        }
+    }
+}
+
+impl PartialEq for DagInit {
+    fn eq(&self, other: &Self) -> bool {
+        self.operator() == other.operator() && self.args().eq(other.args())
+    }
+}
+
+impl Eq for DagInit {}
+
+impl TypedInit {
+    /// A stable, order-sensitive structural digest of this subtree.
+    ///
+    /// Two inits that expand to the same operand/pattern structure produce
+    /// the same digest, which lets backend generators deduplicate them
+    /// instead of emitting a separate table for each.
+    pub fn content_digest(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypedInit;
+
+    // DagInit/ListInit/Record are thin wrappers over a live TableGen init
+    // pointer and can't be constructed outside of a parsed record, so this
+    // only covers the variants that are plain data; the a == b => hash(a)
+    // == hash(b) invariant for those is exercised the same way.
+    fn assert_eq_implies_same_hash(a: &TypedInit, b: &TypedInit) {
+        assert_eq!(a, b);
+        assert_eq!(a.content_digest(), b.content_digest());
+    }
+
+    #[test]
+    fn equal_inits_hash_equal() {
+        assert_eq_implies_same_hash(&TypedInit::Int(7), &TypedInit::Int(7));
+        assert_eq_implies_same_hash(
+            &TypedInit::String("GPR".into()),
+            &TypedInit::String("GPR".into()),
+        );
+        assert_eq_implies_same_hash(&TypedInit::Bits(vec![0, 1, 1]), &TypedInit::Bits(vec![0, 1, 1]));
+    }
+
+    #[test]
+    fn differing_inits_are_not_equal() {
+        assert_ne!(TypedInit::Int(7), TypedInit::Int(8));
+        assert_ne!(TypedInit::Bits(vec![0, 1]), TypedInit::Bits(vec![0, 1, 1]));
+        assert_ne!(TypedInit::String("a".into()), TypedInit::Code("a".into()));
+    }
+
+    // DagInit/ListInit/Record need a live TableGen init pointer to
+    // construct, so JSON encoding is only exercised here for the variants
+    // that are plain data.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_like_dump_json() {
+        assert_eq!(serde_json::to_string(&TypedInit::Int(7)).unwrap(), "7");
+        assert_eq!(serde_json::to_string(&TypedInit::Bit(1)).unwrap(), "1");
+        assert_eq!(
+            serde_json::to_string(&TypedInit::Bits(vec![0, 1, 1])).unwrap(),
+            "[0,1,1]"
+        );
+        assert_eq!(
+            serde_json::to_string(&TypedInit::String("GPR".into())).unwrap(),
+            "\"GPR\""
+        );
+    }
+
+    // DagInit/Record need a live TableGen init pointer to construct, so
+    // this only covers deserializing directly from a single plain-data
+    // TypedInit (Option<T> over a present, non-Invalid value is the case
+    // that used to fail when `option` forwarded to deserialize_any).
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserializes_option_over_a_present_value() {
+        let present: Option<i64> = super::de::from_init(TypedInit::Int(7)).unwrap();
+        assert_eq!(present, Some(7));
+
+        let absent: Option<i64> = super::de::from_init(TypedInit::Invalid).unwrap();
+        assert_eq!(absent, None);
+    }
+}
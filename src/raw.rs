@@ -0,0 +1,67 @@
+// Original work Copyright 2016 Alexander Stocko <as@coder.gg>.
+// Modified work Copyright 2023 Daan Vanoverloop
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Raw FFI declarations over the `tblgen-sys` C shim (`cpp/tblgen_shim.cpp`)
+//! wrapping LLVM's `llvm::RecordKeeper`/`llvm::Init` hierarchy. This module
+//! is intentionally thin: every function here is a 1:1 wrapper generated
+//! from the shim's C header and should not grow any logic of its own.
+
+#![allow(non_snake_case, non_camel_case_types)]
+
+use std::ffi::{c_char, c_void};
+
+pub type TableGenTypedInitRef = *mut c_void;
+pub type TableGenRecordRef = *mut c_void;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableGenRecTyKind {
+    TableGenBitRecTyKind,
+    TableGenBitsRecTyKind,
+    TableGenCodeRecTyKind,
+    TableGenIntRecTyKind,
+    TableGenStringRecTyKind,
+    TableGenListRecTyKind,
+    TableGenDagRecTyKind,
+    TableGenRecordRecTyKind,
+    TableGenInvalidRecTyKind,
+}
+
+extern "C" {
+    pub fn tableGenInitRecType(init: TableGenTypedInitRef) -> TableGenRecTyKind;
+
+    pub fn tableGenBitInitGetValue(init: TableGenTypedInitRef, out: &mut i8) -> bool;
+    pub fn tableGenBitsInitGetValue(init: TableGenTypedInitRef, len: &mut usize) -> *mut i8;
+    pub fn tableGenBitArrayFree(bits: *mut i8);
+    pub fn tableGenIntInitGetValue(init: TableGenTypedInitRef, out: &mut i64) -> bool;
+    pub fn tableGenStringInitGetValueNewString(init: TableGenTypedInitRef) -> *const c_char;
+
+    pub fn tableGenListRecordGet(init: TableGenTypedInitRef, index: usize) -> TableGenTypedInitRef;
+    pub fn tableGenListRecordNumElements(init: TableGenTypedInitRef) -> usize;
+
+    pub fn tableGenDagRecordGet(init: TableGenTypedInitRef, index: usize) -> TableGenTypedInitRef;
+    pub fn tableGenDagRecordArgName(init: TableGenTypedInitRef, index: usize) -> *const c_char;
+    pub fn tableGenDagRecordNumArgs(init: TableGenTypedInitRef) -> usize;
+    /// The dag's operator node (e.g. the `ins` in `(ins GPR:$a, GPR:$b)`).
+    pub fn tableGenDagRecordGetOperator(init: TableGenTypedInitRef) -> TableGenTypedInitRef;
+    /// The name of the dag's operator, if it names a def rather than an
+    /// anonymous expression.
+    pub fn tableGenDagRecordOperatorName(init: TableGenTypedInitRef) -> *const c_char;
+
+    pub fn tableGenDefInitGetValue(init: TableGenTypedInitRef) -> TableGenRecordRef;
+
+    pub fn tableGenRecordName(record: TableGenRecordRef) -> *const c_char;
+    pub fn tableGenRecordNumFields(record: TableGenRecordRef) -> usize;
+    pub fn tableGenRecordFieldName(record: TableGenRecordRef, index: usize) -> *const c_char;
+    pub fn tableGenRecordGetValue(
+        record: TableGenRecordRef,
+        name: *const c_char,
+    ) -> TableGenTypedInitRef;
+}
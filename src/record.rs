@@ -0,0 +1,63 @@
+// Original work Copyright 2016 Alexander Stocko <as@coder.gg>.
+// Modified work Copyright 2023 Daan Vanoverloop
+// See the COPYRIGHT file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::init::TypedInit;
+use crate::raw::{
+    tableGenRecordFieldName, tableGenRecordGetValue, tableGenRecordName, tableGenRecordNumFields,
+    TableGenRecordRef,
+};
+use std::ffi::CStr;
+
+/// A parsed TableGen record (a `def` or `class` instantiation), e.g.
+/// `ADDrr` or `Instruction`.
+#[derive(Debug)]
+pub struct Record {
+    raw: TableGenRecordRef,
+}
+
+impl Record {
+    pub fn from_raw(raw: TableGenRecordRef) -> Record {
+        Record { raw }
+    }
+
+    pub fn name(&self) -> &str {
+        unsafe {
+            CStr::from_ptr(tableGenRecordName(self.raw))
+                .to_str()
+                .unwrap_or_default()
+        }
+    }
+
+    /// The names of this record's fields, in declaration order.
+    pub fn fields(&self) -> Vec<String> {
+        let num_fields = unsafe { tableGenRecordNumFields(self.raw) };
+        (0..num_fields)
+            .filter_map(|i| {
+                let name = unsafe { tableGenRecordFieldName(self.raw, i) };
+                if name.is_null() {
+                    None
+                } else {
+                    Some(unsafe { CStr::from_ptr(name).to_string_lossy().into_owned() })
+                }
+            })
+            .collect()
+    }
+
+    /// The value of the field named `name`, if the record has one.
+    pub fn value(&self, name: &str) -> Option<TypedInit> {
+        let cname = std::ffi::CString::new(name).ok()?;
+        let value = unsafe { tableGenRecordGetValue(self.raw, cname.as_ptr()) };
+        if value.is_null() {
+            None
+        } else {
+            unsafe { TypedInit::from_raw(value).ok() }
+        }
+    }
+}